@@ -0,0 +1,23 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    NotInitialized = 1,
+    AlreadyInitialized = 2,
+    AgreementNotFound = 3,
+    AgreementAlreadyExists = 4,
+    InvalidRentAmount = 5,
+    InvalidDateRange = 6,
+    InvalidCommissionRate = 7,
+    InvalidStatusTransition = 8,
+    UnauthorizedSigner = 9,
+    SignatureAlreadyRecorded = 10,
+    RentAlreadyPaid = 11,
+    EscrowNotHeld = 12,
+    DocumentNotFound = 13,
+    InvalidTtlConfig = 14,
+    EscrowAlreadyHeld = 15,
+    EscrowNotReleasable = 16,
+}
@@ -0,0 +1,82 @@
+use soroban_sdk::{Address, BytesN, Env, String, Symbol};
+
+use crate::types::AgreementStatus;
+
+pub(crate) fn agreement_created_event(env: &Env, agreement_id: &String) {
+    env.events().publish(
+        (Symbol::new(env, "agreement_created_event"),),
+        agreement_id.clone(),
+    );
+}
+
+pub(crate) fn status_transitioned_event(
+    env: &Env,
+    agreement_id: &String,
+    old_status: AgreementStatus,
+    new_status: AgreementStatus,
+) {
+    env.events().publish(
+        (Symbol::new(env, "status_transitioned_event"),),
+        (agreement_id.clone(), old_status, new_status),
+    );
+}
+
+pub(crate) fn signature_collected_event(
+    env: &Env,
+    agreement_id: &String,
+    signer: &Address,
+    nonce: u64,
+) {
+    env.events().publish(
+        (Symbol::new(env, "signature_collected_event"),),
+        (agreement_id.clone(), signer.clone(), nonce),
+    );
+}
+
+pub(crate) fn agreement_activated_event(env: &Env, agreement_id: &String) {
+    env.events().publish(
+        (Symbol::new(env, "agreement_activated_event"),),
+        agreement_id.clone(),
+    );
+}
+
+pub(crate) fn rent_paid_event(
+    env: &Env,
+    agreement_id: &String,
+    period: u32,
+    landlord_share: i128,
+    commission: i128,
+) {
+    env.events().publish(
+        (Symbol::new(env, "rent_paid_event"),),
+        (agreement_id.clone(), period, landlord_share, commission),
+    );
+}
+
+pub(crate) fn escrow_released_event(env: &Env, agreement_id: &String, to: &Address, amount: i128) {
+    env.events().publish(
+        (Symbol::new(env, "escrow_released_event"),),
+        (agreement_id.clone(), to.clone(), amount),
+    );
+}
+
+pub(crate) fn ttl_extended_event(env: &Env, agreement_id: &String, extend_to: u32) {
+    env.events().publish(
+        (Symbol::new(env, "ttl_extended_event"),),
+        (agreement_id.clone(), extend_to),
+    );
+}
+
+pub(crate) fn document_attached_event(env: &Env, agreement_id: &String, doc_hash: &BytesN<32>) {
+    env.events().publish(
+        (Symbol::new(env, "document_attached_event"),),
+        (agreement_id.clone(), doc_hash.clone()),
+    );
+}
+
+pub(crate) fn access_granted_event(env: &Env, agreement_id: &String, grantee: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "access_granted_event"),),
+        (agreement_id.clone(), grantee.clone()),
+    );
+}
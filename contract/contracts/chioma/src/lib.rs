@@ -0,0 +1,562 @@
+#![no_std]
+
+mod error;
+mod events;
+mod types;
+
+#[cfg(test)]
+mod test;
+
+use error::Error;
+use types::{
+    AgreementPage, AgreementStatus, DataKey, Document, DocumentView, RentAgreement, SortOrder,
+    TtlConfig,
+};
+
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, token, vec, Address, Bytes, BytesN, Env, Map,
+    String, Vec,
+};
+
+/// Hard cap on `list_agreements` page size, independent of what a caller asks for.
+const MAX_LIST_LIMIT: u32 = 50;
+
+/// Hard cap on how many creation ordinals a single `list_agreements` call will
+/// visit, regardless of how many of them match `filter`. Keeps a filtered scan
+/// O(limit)-ish instead of O(total); callers that hit this bound get back a
+/// `next_start` to resume from rather than a silently-truncated result.
+const MAX_SCAN_WINDOW: u32 = MAX_LIST_LIMIT * 10;
+
+/// Default TTL thresholds (in ledgers) used until `configure_ttl` is called.
+const DEFAULT_MIN_TTL: u32 = 17_280; // ~1 day at 5s ledgers
+const DEFAULT_EXTEND_TO: u32 = 535_680; // ~31 days at 5s ledgers
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn hello(env: Env, to: String) -> Vec<String> {
+        vec![&env, String::from_str(&env, "Hello"), to]
+    }
+
+    pub fn create_agreement(
+        env: Env,
+        agreement_id: String,
+        landlord: Address,
+        tenant: Address,
+        agent: Option<Address>,
+        monthly_rent: i128,
+        security_deposit: i128,
+        start_date: u64,
+        end_date: u64,
+        agent_commission_rate: u32,
+    ) -> RentAgreement {
+        let key = DataKey::Agreement(agreement_id.clone());
+        if env.storage().persistent().has(&key) {
+            panic_with_error!(&env, Error::AgreementAlreadyExists);
+        }
+        if monthly_rent <= 0 || security_deposit < 0 {
+            panic_with_error!(&env, Error::InvalidRentAmount);
+        }
+        if end_date <= start_date {
+            panic_with_error!(&env, Error::InvalidDateRange);
+        }
+        if agent_commission_rate > 100 || (agent.is_none() && agent_commission_rate > 0) {
+            panic_with_error!(&env, Error::InvalidCommissionRate);
+        }
+
+        let agreement = RentAgreement {
+            agreement_id: agreement_id.clone(),
+            landlord,
+            tenant,
+            agent,
+            monthly_rent,
+            security_deposit,
+            start_date,
+            end_date,
+            agent_commission_rate,
+            status: AgreementStatus::Draft,
+        };
+
+        env.storage().persistent().set(&key, &agreement);
+
+        let count: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::AgreementCount)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::AgreementIndex(count), &agreement_id);
+        env.storage()
+            .instance()
+            .set(&DataKey::AgreementCount, &(count + 1));
+
+        let config = ttl_config(&env);
+        let lease_ledgers = (end_date - start_date) as u32;
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, config.min_ttl, lease_ledgers.max(config.extend_to));
+        env.storage()
+            .instance()
+            .extend_ttl(config.min_ttl, config.extend_to);
+
+        events::agreement_created_event(&env, &agreement_id);
+
+        agreement
+    }
+
+    /// Moves `agreement_id` to `new_status`, rejecting any move not present
+    /// in `types::allowed_next` for its current status. Authorized by the landlord.
+    pub fn transition_status(
+        env: Env,
+        agreement_id: String,
+        new_status: AgreementStatus,
+    ) -> RentAgreement {
+        let key = DataKey::Agreement(agreement_id.clone());
+        let mut agreement: RentAgreement = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::AgreementNotFound));
+
+        agreement.landlord.require_auth();
+
+        let old_status = agreement.status;
+        if !types::allowed_next(old_status).contains(&new_status) {
+            panic_with_error!(&env, Error::InvalidStatusTransition);
+        }
+
+        agreement.status = new_status;
+        env.storage().persistent().set(&key, &agreement);
+
+        events::status_transitioned_event(&env, &agreement_id, old_status, new_status);
+
+        agreement
+    }
+
+    /// Admin getter: every status `transition_status` can move `status` to in
+    /// one hop. `Active`, reached only through `sign_agreement`, is not listed.
+    pub fn reachable_statuses(env: Env, status: AgreementStatus) -> Vec<AgreementStatus> {
+        let mut out = Vec::new(&env);
+        for next in types::allowed_next(status) {
+            out.push_back(*next);
+        }
+        out
+    }
+
+    /// Admin getter: every variant `AgreementStatus` can ever take, so
+    /// callers (e.g. a UI building a status filter) don't need their own
+    /// copy of the enum's variants.
+    pub fn all_statuses(env: Env) -> Vec<AgreementStatus> {
+        let mut out = Vec::new(&env);
+        for status in types::ALL {
+            out.push_back(status);
+        }
+        out
+    }
+
+    /// Records `signer`'s signature on an agreement that is awaiting
+    /// signatures, advancing it to `Active` once every required party
+    /// (landlord, tenant, and the agent if one is named) has signed.
+    pub fn sign_agreement(env: Env, agreement_id: String, signer: Address) -> RentAgreement {
+        signer.require_auth();
+
+        let key = DataKey::Agreement(agreement_id.clone());
+        let mut agreement: RentAgreement = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::AgreementNotFound));
+
+        if agreement.status != AgreementStatus::PendingSignatures {
+            panic_with_error!(&env, Error::InvalidStatusTransition);
+        }
+
+        let is_party = signer == agreement.landlord
+            || signer == agreement.tenant
+            || Some(signer.clone()) == agreement.agent;
+        if !is_party {
+            panic_with_error!(&env, Error::UnauthorizedSigner);
+        }
+
+        // The nonce, not the `signatures` flag, is the actual replay guard:
+        // a nonzero nonce means this signer's signature on this agreement
+        // has already been consumed.
+        let nonce_key = DataKey::SignatureNonce(agreement_id.clone(), signer.clone());
+        let nonce: u64 = env.storage().persistent().get(&nonce_key).unwrap_or(0);
+        if nonce != 0 {
+            panic_with_error!(&env, Error::SignatureAlreadyRecorded);
+        }
+        env.storage().persistent().set(&nonce_key, &(nonce + 1));
+
+        let signatures_key = DataKey::Signatures(agreement_id.clone());
+        let mut signatures: Map<Address, bool> = env
+            .storage()
+            .persistent()
+            .get(&signatures_key)
+            .unwrap_or_else(|| Map::new(&env));
+        signatures.set(signer.clone(), true);
+        env.storage().persistent().set(&signatures_key, &signatures);
+
+        events::signature_collected_event(&env, &agreement_id, &signer, nonce);
+
+        let landlord_signed = signatures.get(agreement.landlord.clone()).unwrap_or(false);
+        let tenant_signed = signatures.get(agreement.tenant.clone()).unwrap_or(false);
+        let agent_signed = match &agreement.agent {
+            Some(agent) => signatures.get(agent.clone()).unwrap_or(false),
+            None => true,
+        };
+
+        if landlord_signed && tenant_signed && agent_signed {
+            agreement.status = AgreementStatus::Active;
+            env.storage().persistent().set(&key, &agreement);
+            events::agreement_activated_event(&env, &agreement_id);
+        }
+
+        agreement
+    }
+
+    /// One-time admin bootstrap. Must be called before `set_payment_token`
+    /// or `configure_ttl`, ideally in the same transaction as deployment.
+    pub fn initialize(env: Env, admin: Address) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, Error::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
+    /// One-time setup of the SAC token used for rent and deposit transfers,
+    /// authorized by the admin set in `initialize`.
+    pub fn set_payment_token(env: Env, token: Address) {
+        admin(&env).require_auth();
+
+        if env.storage().instance().has(&DataKey::PaymentToken) {
+            panic_with_error!(&env, Error::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::PaymentToken, &token);
+    }
+
+    /// Transfers `monthly_rent` from the tenant to the landlord for `period`,
+    /// splitting `agent_commission_rate` percent to the agent when one is
+    /// named. Each `(agreement_id, period)` can only be paid once.
+    pub fn pay_rent(env: Env, agreement_id: String, period: u32) -> i128 {
+        let agreement: RentAgreement = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Agreement(agreement_id.clone()))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::AgreementNotFound));
+
+        let payment_key = DataKey::RentPayment(agreement_id.clone(), period);
+        if env.storage().persistent().has(&payment_key) {
+            panic_with_error!(&env, Error::RentAlreadyPaid);
+        }
+
+        agreement.tenant.require_auth();
+
+        let token_client = token::Client::new(&env, &payment_token(&env));
+        let commission =
+            (agreement.monthly_rent * agreement.agent_commission_rate as i128) / 100;
+        let landlord_share = agreement.monthly_rent - commission;
+
+        token_client.transfer(&agreement.tenant, &agreement.landlord, &landlord_share);
+        if commission > 0 {
+            if let Some(agent) = &agreement.agent {
+                token_client.transfer(&agreement.tenant, agent, &commission);
+            }
+        }
+
+        env.storage().persistent().set(&payment_key, &true);
+
+        events::rent_paid_event(&env, &agreement_id, period, landlord_share, commission);
+
+        agreement.monthly_rent
+    }
+
+    /// Pulls the security deposit from the tenant into the contract's own
+    /// address, where it sits until `release_escrow` pays it out.
+    pub fn deposit_escrow(env: Env, agreement_id: String) {
+        let agreement: RentAgreement = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Agreement(agreement_id.clone()))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::AgreementNotFound));
+
+        let escrow_key = DataKey::EscrowHeld(agreement_id.clone());
+        if env.storage().persistent().has(&escrow_key) {
+            panic_with_error!(&env, Error::EscrowAlreadyHeld);
+        }
+
+        agreement.tenant.require_auth();
+
+        let token_client = token::Client::new(&env, &payment_token(&env));
+        token_client.transfer(
+            &agreement.tenant,
+            &env.current_contract_address(),
+            &agreement.security_deposit,
+        );
+
+        env.storage().persistent().set(&escrow_key, &true);
+    }
+
+    /// Pays the held security deposit out to `to`, authorized by the landlord,
+    /// once the agreement is `Terminated` or `Disputed`; an `Expired`
+    /// agreement is rejected.
+    pub fn release_escrow(env: Env, agreement_id: String, to: Address) {
+        let agreement: RentAgreement = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Agreement(agreement_id.clone()))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::AgreementNotFound));
+
+        agreement.landlord.require_auth();
+
+        if !matches!(
+            agreement.status,
+            AgreementStatus::Terminated | AgreementStatus::Disputed
+        ) {
+            panic_with_error!(&env, Error::EscrowNotReleasable);
+        }
+
+        let escrow_key = DataKey::EscrowHeld(agreement_id.clone());
+        let held: bool = env.storage().persistent().get(&escrow_key).unwrap_or(false);
+        if !held {
+            panic_with_error!(&env, Error::EscrowNotHeld);
+        }
+
+        let token_client = token::Client::new(&env, &payment_token(&env));
+        token_client.transfer(
+            &env.current_contract_address(),
+            &to,
+            &agreement.security_deposit,
+        );
+
+        env.storage().persistent().remove(&escrow_key);
+
+        events::escrow_released_event(&env, &agreement_id, &to, agreement.security_deposit);
+    }
+
+    pub fn get_agreement(env: Env, agreement_id: String) -> RentAgreement {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Agreement(agreement_id))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::AgreementNotFound))
+    }
+
+    /// Returns a page of at most `limit` (capped at `MAX_LIST_LIMIT`) agreements
+    /// starting at creation ordinal `start`, optionally filtered by status and
+    /// sorted, alongside the total number of agreements ever created.
+    ///
+    /// A `filter` that matches sparsely can exhaust `MAX_SCAN_WINDOW` before
+    /// collecting `limit` items; callers should always pass `next_start` back
+    /// in as `start` to continue rather than assuming a short page means
+    /// there's nothing left.
+    pub fn list_agreements(
+        env: Env,
+        start: u32,
+        limit: u32,
+        filter: Option<AgreementStatus>,
+        sort: SortOrder,
+    ) -> AgreementPage {
+        let total: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::AgreementCount)
+            .unwrap_or(0);
+        let capped_limit = limit.min(MAX_LIST_LIMIT);
+        let scan_end = total.min(start.saturating_add(MAX_SCAN_WINDOW));
+
+        let mut items: Vec<RentAgreement> = Vec::new(&env);
+        let mut ordinal = start;
+        while ordinal < scan_end && items.len() < capped_limit {
+            if let Some(id) = env
+                .storage()
+                .instance()
+                .get::<DataKey, String>(&DataKey::AgreementIndex(ordinal))
+            {
+                // An archived (TTL-expired) entry is skipped rather than
+                // unwrapped: one stale record shouldn't take down every
+                // caller's listing, just its own slot in the page.
+                if let Some(agreement) = env
+                    .storage()
+                    .persistent()
+                    .get::<DataKey, RentAgreement>(&DataKey::Agreement(id))
+                {
+                    if filter.map_or(true, |f| agreement.status == f) {
+                        items.push_back(agreement);
+                    }
+                }
+            }
+            ordinal += 1;
+        }
+
+        if let SortOrder::ByStartDate = sort {
+            sort_by_start_date(&mut items);
+        }
+
+        AgreementPage {
+            items,
+            total,
+            next_start: ordinal,
+        }
+    }
+
+    /// Tunes the `extend_ttl` thresholds applied to agreement and bookkeeping
+    /// entries, authorized by the admin. `min_ttl` must not exceed
+    /// `extend_to`, or every subsequent `extend_ttl` call would panic.
+    pub fn configure_ttl(env: Env, min_ttl: u32, extend_to: u32) {
+        admin(&env).require_auth();
+
+        if min_ttl > extend_to {
+            panic_with_error!(&env, Error::InvalidTtlConfig);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::TtlConfig, &TtlConfig { min_ttl, extend_to });
+    }
+
+    /// Tops up the TTL of an agreement (and the contract's own bookkeeping
+    /// entries) so a long-lived lease isn't archived out from under it.
+    pub fn renew_agreement_ttl(env: Env, agreement_id: String) {
+        let key = DataKey::Agreement(agreement_id.clone());
+        if !env.storage().persistent().has(&key) {
+            panic_with_error!(&env, Error::AgreementNotFound);
+        }
+
+        let config = ttl_config(&env);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, config.min_ttl, config.extend_to);
+        env.storage()
+            .instance()
+            .extend_ttl(config.min_ttl, config.extend_to);
+
+        events::ttl_extended_event(&env, &agreement_id, config.extend_to);
+    }
+
+    /// Anchors a signed lease document's content hash and an opaque
+    /// reference to its off-chain encryption key, authorized by the landlord.
+    pub fn attach_document(env: Env, agreement_id: String, doc_hash: BytesN<32>, key_ref: Bytes) {
+        let agreement = Self::get_agreement(env.clone(), agreement_id.clone());
+        agreement.landlord.require_auth();
+
+        let document = Document {
+            doc_hash: doc_hash.clone(),
+            key_ref,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Document(agreement_id.clone()), &document);
+
+        events::document_attached_event(&env, &agreement_id, &doc_hash);
+    }
+
+    /// Grants `grantee` read access to a document's `key_ref`, authorized by the landlord.
+    pub fn grant_document_access(env: Env, agreement_id: String, grantee: Address) {
+        let agreement = Self::get_agreement(env.clone(), agreement_id.clone());
+        agreement.landlord.require_auth();
+
+        let access_key = DataKey::DocumentAccess(agreement_id.clone());
+        let mut acl: Map<Address, bool> = env
+            .storage()
+            .persistent()
+            .get(&access_key)
+            .unwrap_or_else(|| Map::new(&env));
+        acl.set(grantee.clone(), true);
+        env.storage().persistent().set(&access_key, &acl);
+
+        events::access_granted_event(&env, &agreement_id, &grantee);
+    }
+
+    /// Revokes a previously granted document access, authorized by the landlord.
+    pub fn revoke_document_access(env: Env, agreement_id: String, grantee: Address) {
+        let agreement = Self::get_agreement(env.clone(), agreement_id.clone());
+        agreement.landlord.require_auth();
+
+        let access_key = DataKey::DocumentAccess(agreement_id.clone());
+        let mut acl: Map<Address, bool> = env
+            .storage()
+            .persistent()
+            .get(&access_key)
+            .unwrap_or_else(|| Map::new(&env));
+        acl.remove(grantee);
+        env.storage().persistent().set(&access_key, &acl);
+    }
+
+    /// Returns a document's hash to any caller, and its `key_ref` only to
+    /// parties on the agreement or addresses granted access.
+    pub fn get_document(env: Env, agreement_id: String, requester: Address) -> DocumentView {
+        requester.require_auth();
+
+        let agreement = Self::get_agreement(env.clone(), agreement_id.clone());
+        let document: Document = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Document(agreement_id.clone()))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::DocumentNotFound));
+
+        let is_party = requester == agreement.landlord
+            || requester == agreement.tenant
+            || Some(requester.clone()) == agreement.agent;
+
+        let acl: Map<Address, bool> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DocumentAccess(agreement_id))
+            .unwrap_or_else(|| Map::new(&env));
+        let is_granted = acl.get(requester).unwrap_or(false);
+
+        DocumentView {
+            doc_hash: document.doc_hash,
+            key_ref: if is_party || is_granted {
+                Some(document.key_ref)
+            } else {
+                None
+            },
+        }
+    }
+}
+
+fn ttl_config(env: &Env) -> TtlConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::TtlConfig)
+        .unwrap_or(TtlConfig {
+            min_ttl: DEFAULT_MIN_TTL,
+            extend_to: DEFAULT_EXTEND_TO,
+        })
+}
+
+/// Insertion sort by `start_date`; `soroban_sdk::Vec` has no built-in sort.
+fn sort_by_start_date(items: &mut Vec<RentAgreement>) {
+    for i in 1..items.len() {
+        let mut j = i;
+        while j > 0 {
+            let a = items.get(j - 1).unwrap();
+            let b = items.get(j).unwrap();
+            if a.start_date > b.start_date {
+                items.set(j - 1, b);
+                items.set(j, a);
+                j -= 1;
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+fn payment_token(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&DataKey::PaymentToken)
+        .unwrap_or_else(|| panic_with_error!(env, Error::NotInitialized))
+}
+
+fn admin(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .unwrap_or_else(|| panic_with_error!(env, Error::NotInitialized))
+}
@@ -0,0 +1,134 @@
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, String};
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AgreementStatus {
+    Draft,
+    PendingSignatures,
+    Active,
+    Terminated,
+    Expired,
+    Disputed,
+}
+
+/// Every variant of `AgreementStatus`, in declaration order. Backs the
+/// `all_statuses` admin getter.
+pub const ALL: [AgreementStatus; 6] = [
+    AgreementStatus::Draft,
+    AgreementStatus::PendingSignatures,
+    AgreementStatus::Active,
+    AgreementStatus::Terminated,
+    AgreementStatus::Expired,
+    AgreementStatus::Disputed,
+];
+
+/// States reachable directly from `status` via a single `transition_status` call.
+pub fn allowed_next(status: AgreementStatus) -> &'static [AgreementStatus] {
+    match status {
+        AgreementStatus::Draft => &[AgreementStatus::PendingSignatures],
+        // `Active` is deliberately absent here: it's only reachable through
+        // `sign_agreement`'s multi-party auth, never a bare `transition_status` call.
+        AgreementStatus::PendingSignatures => &[AgreementStatus::Terminated],
+        AgreementStatus::Active => &[
+            AgreementStatus::Terminated,
+            AgreementStatus::Expired,
+            AgreementStatus::Disputed,
+        ],
+        AgreementStatus::Disputed => &[AgreementStatus::Terminated],
+        AgreementStatus::Terminated | AgreementStatus::Expired => &[],
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RentAgreement {
+    pub agreement_id: String,
+    pub landlord: Address,
+    pub tenant: Address,
+    pub agent: Option<Address>,
+    pub monthly_rent: i128,
+    pub security_deposit: i128,
+    pub start_date: u64,
+    pub end_date: u64,
+    pub agent_commission_rate: u32,
+    pub status: AgreementStatus,
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub enum DataKey {
+    Agreement(String),
+    AgreementCount,
+    /// Contract admin, set once via `initialize` and required to authorize
+    /// contract-wide configuration (payment token, TTL thresholds).
+    Admin,
+    /// Per-party signed flag for an agreement, keyed by the agreement id.
+    Signatures(String),
+    /// Monotonically increasing nonce per `(agreement_id, signer)`, bumped
+    /// on every accepted signature so a consumed signature can never be
+    /// replayed.
+    SignatureNonce(String, Address),
+    /// SAC token contract used to move rent and deposit value.
+    PaymentToken,
+    /// Whether `monthly_rent` has already been paid for `(agreement_id, period)`.
+    RentPayment(String, u32),
+    /// Whether the security deposit for an agreement is currently held in escrow.
+    EscrowHeld(String),
+    /// Maps creation ordinal -> agreement id, so listing can page without
+    /// scanning every stored agreement.
+    AgreementIndex(u32),
+    /// Tunable TTL thresholds applied to agreement and bookkeeping entries.
+    TtlConfig,
+    /// Anchored off-chain lease document for an agreement.
+    Document(String),
+    /// Access control list of addresses granted read access to a document's
+    /// `key_ref`, beyond the parties already on the agreement.
+    DocumentAccess(String),
+}
+
+/// A content hash plus an opaque reference to an off-chain encryption key
+/// for the signed lease document. `key_ref` is only ever returned to
+/// authorized readers; `doc_hash` is public.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct Document {
+    pub doc_hash: BytesN<32>,
+    pub key_ref: Bytes,
+}
+
+/// `get_document` response: `key_ref` is `None` for callers without access.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct DocumentView {
+    pub doc_hash: BytesN<32>,
+    pub key_ref: Option<Bytes>,
+}
+
+/// `min_ttl` is the `extend_ttl` threshold (bump once remaining TTL falls
+/// below this many ledgers); `extend_to` is how many ledgers to extend to.
+#[contracttype]
+#[derive(Clone, Copy, Debug)]
+pub struct TtlConfig {
+    pub min_ttl: u32,
+    pub extend_to: u32,
+}
+
+/// Sort order for `list_agreements`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SortOrder {
+    ById,
+    ByStartDate,
+}
+
+/// A bounded page of agreements, the total number of agreements ever
+/// created, and the ordinal a caller should pass as `start` to continue
+/// past this page (relevant when a `filter` caused the scan window to be
+/// exhausted before `limit` matches were found).
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AgreementPage {
+    pub items: soroban_sdk::Vec<RentAgreement>,
+    pub total: u32,
+    pub next_start: u32,
+}